@@ -7,9 +7,12 @@ use libarc2::registers::{IOMask, AuxDACFn};
 use ndarray::{Ix1, Ix2, Array};
 use numpy::{PyArray, PyReadonlyArray};
 use std::convert::{From, Into, TryInto};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use numpy::convert::IntoPyArray;
 use pyo3::prelude::{pymodule, pyclass, pymethods};
-use pyo3::prelude::{PyModule, PyRefMut, PyResult, Python, PyErr};
+use pyo3::prelude::{PyModule, Py, PyRef, PyRefMut, PyCell, PyResult, Python, PyErr, PyObject, IntoPy};
+use pyo3::types::PyDict;
 use pyo3::create_exception;
 use pyo3::exceptions;
 use pyo3::intern;
@@ -334,6 +337,91 @@ impl From<PyReadType> for ReadType {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum InfoKey {
+    DACVoltageMin,
+    DACVoltageMax,
+    ADCResolution,
+    ADCLsb,
+    NumChannels,
+    BufferCapacity,
+    MinPulseWidth,
+    TimingGranularity
+}
+
+impl InfoKey {
+    fn as_str(&self) -> &'static str {
+        match self {
+            InfoKey::DACVoltageMin => "DACVoltageMin",
+            InfoKey::DACVoltageMax => "DACVoltageMax",
+            InfoKey::ADCResolution => "ADCResolution",
+            InfoKey::ADCLsb => "ADCLsb",
+            InfoKey::NumChannels => "NumChannels",
+            InfoKey::BufferCapacity => "BufferCapacity",
+            InfoKey::MinPulseWidth => "MinPulseWidth",
+            InfoKey::TimingGranularity => "TimingGranularity"
+        }
+    }
+
+    fn all() -> [InfoKey; 8] {
+        [InfoKey::DACVoltageMin, InfoKey::DACVoltageMax, InfoKey::ADCResolution,
+         InfoKey::ADCLsb, InfoKey::NumChannels, InfoKey::BufferCapacity,
+         InfoKey::MinPulseWidth, InfoKey::TimingGranularity]
+    }
+}
+
+/// Identifier for a hardware capability queried with
+/// :meth:`pyarc2.Instrument.info`.
+///
+/// :var DACVoltageMin: Minimum DAC output voltage
+/// :var DACVoltageMax: Maximum DAC output voltage
+/// :var ADCResolution: ADC resolution in bits
+/// :var ADCLsb: ADC least significant bit, in volts
+/// :var NumChannels: Number of available channels
+/// :var BufferCapacity: Long-operation buffer capacity, in records
+/// :var MinPulseWidth: Minimum pulse width, in nanoseconds
+/// :var TimingGranularity: Smallest representable timing increment, in nanoseconds
+#[pyclass(name="InfoKey", module="pyarc2")]
+#[derive(Clone)]
+struct PyInfoKey { _inner: InfoKey }
+
+#[allow(non_snake_case)]
+#[pymethods]
+impl PyInfoKey {
+
+    #[classattr]
+    fn DACVoltageMin() -> PyInfoKey { PyInfoKey { _inner: InfoKey::DACVoltageMin } }
+
+    #[classattr]
+    fn DACVoltageMax() -> PyInfoKey { PyInfoKey { _inner: InfoKey::DACVoltageMax } }
+
+    #[classattr]
+    fn ADCResolution() -> PyInfoKey { PyInfoKey { _inner: InfoKey::ADCResolution } }
+
+    #[classattr]
+    fn ADCLsb() -> PyInfoKey { PyInfoKey { _inner: InfoKey::ADCLsb } }
+
+    #[classattr]
+    fn NumChannels() -> PyInfoKey { PyInfoKey { _inner: InfoKey::NumChannels } }
+
+    #[classattr]
+    fn BufferCapacity() -> PyInfoKey { PyInfoKey { _inner: InfoKey::BufferCapacity } }
+
+    #[classattr]
+    fn MinPulseWidth() -> PyInfoKey { PyInfoKey { _inner: InfoKey::MinPulseWidth } }
+
+    #[classattr]
+    fn TimingGranularity() -> PyInfoKey { PyInfoKey { _inner: InfoKey::TimingGranularity } }
+
+    fn __str__(&self) -> &'static str {
+        self._inner.as_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("InfoKey<{}>", self._inner.as_str())
+    }
+}
+
 /// Wait condition for long running operations, such as
 /// :meth:`pyarc2.Instrument.read_train`.
 #[pyclass(name="WaitFor", module="pyarc2")]
@@ -384,6 +472,312 @@ impl From<PyWaitFor> for WaitFor {
     }
 }
 
+/// Deterministic xorshift64 PRNG used internally to permute scan order
+/// when a ``seed`` is given. Kept local so that voltage scans do not pull
+/// in an external RNG dependency for what is just a reproducible shuffle.
+struct Xorshift64 { state: u64 }
+
+impl Xorshift64 {
+    fn new(seed: u64) -> Self {
+        Xorshift64 { state: if seed == 0 { 0x9e3779b97f4a7c15 } else { seed } }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Fisher-Yates shuffle, in place
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.next_u64() as usize) % (i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+/// VoltageScan materialises the voltage setpoints of a sweep so they can be
+/// fed into :meth:`pyarc2.Instrument.generate_ramp_scan`. Use
+/// :meth:`~pyarc2.VoltageScan.Range` for a linear sweep between two voltages
+/// or :meth:`~pyarc2.VoltageScan.Center` for a sweep expanding symmetrically
+/// outward from a center voltage, then reorder the resulting points with
+/// :meth:`~pyarc2.VoltageScan.order`.
+#[pyclass(name="VoltageScan", module="pyarc2")]
+#[derive(Clone)]
+struct PyVoltageScan { _voltages: Vec<f32> }
+
+#[allow(non_snake_case)]
+#[pymethods]
+impl PyVoltageScan {
+
+    /// Range(start, stop, step, /)
+    /// --
+    ///
+    /// Build a linear scan from ``start`` to ``stop`` (inclusive) in increments of
+    /// ``step``. If ``step`` does not evenly divide ``stop - start`` the last
+    /// point is clamped to ``stop`` so the endpoint is always included.
+    ///
+    /// :param float start: First voltage of the scan
+    /// :param float stop: Last voltage of the scan
+    /// :param float step: Voltage increment; must be strictly positive
+    /// :raises ValueError: If ``step`` is zero or negative
+    #[staticmethod]
+    fn Range(start: f32, stop: f32, step: f32) -> PyResult<PyVoltageScan> {
+
+        if step <= 0.0 {
+            return Err(exceptions::PyValueError::new_err("step must be strictly positive"));
+        }
+
+        let mut voltages = Vec::new();
+        let direction = if stop >= start { 1.0 } else { -1.0 };
+        let mut v = start;
+
+        loop {
+            if (direction > 0.0 && v >= stop) || (direction < 0.0 && v <= stop) {
+                voltages.push(stop);
+                break;
+            }
+            voltages.push(v);
+            v += direction * step;
+        }
+
+        Ok(PyVoltageScan { _voltages: voltages })
+    }
+
+    /// Center(center, span, step, /)
+    /// --
+    ///
+    /// Build a scan expanding symmetrically outward from ``center`` covering
+    /// ``[center - span/2, center + span/2]`` in increments of ``step``. A
+    /// ``span`` of zero yields the single point ``center``.
+    ///
+    /// :param float center: The voltage the scan is centered on
+    /// :param float span: The total width of the scan
+    /// :param float step: Voltage increment; must be strictly positive
+    /// :raises ValueError: If ``step`` is zero or negative
+    #[staticmethod]
+    fn Center(center: f32, span: f32, step: f32) -> PyResult<PyVoltageScan> {
+
+        if step <= 0.0 {
+            return Err(exceptions::PyValueError::new_err("step must be strictly positive"));
+        }
+
+        if span == 0.0 {
+            return Ok(PyVoltageScan { _voltages: vec![center] });
+        }
+
+        let half = span.abs() / 2.0;
+        let mut offsets = Vec::new();
+        let mut k = 1.0f32;
+        while k * step < half {
+            offsets.push(k * step);
+            k += 1.0;
+        }
+        offsets.push(half);
+
+        let mut voltages: Vec<f32> = offsets.iter().rev().map(|o| center - o).collect();
+        voltages.push(center);
+        voltages.extend(offsets.iter().map(|o| center + o));
+
+        Ok(PyVoltageScan { _voltages: voltages })
+    }
+
+    /// order(self, order, seed, /)
+    /// --
+    ///
+    /// Reorder the materialized voltage points of this scan and return a new
+    /// ``VoltageScan``.
+    ///
+    /// :param str order: One of ``linear`` (no change), ``random`` (shuffled with
+    ///                   the seeded PRNG so the same ``seed`` always yields the
+    ///                   same permutation) or ``bidirectional`` (the scan followed
+    ///                   by its reverse, for up-then-down hysteresis loops)
+    /// :param int seed: Seed for the PRNG; only used when ``order`` is ``random``
+    /// :return: A new, reordered ``VoltageScan``
+    /// :raises ValueError: If ``order`` is not one of the supported values
+    fn order(&self, order: &str, seed: Option<u64>) -> PyResult<PyVoltageScan> {
+
+        match order {
+            "linear" => Ok(self.clone()),
+            "random" => {
+                let mut voltages = self._voltages.clone();
+                let mut rng = Xorshift64::new(seed.unwrap_or(1));
+                rng.shuffle(&mut voltages);
+                Ok(PyVoltageScan { _voltages: voltages })
+            },
+            "bidirectional" => {
+                let mut voltages = self._voltages.clone();
+                voltages.extend(self._voltages.iter().rev());
+                Ok(PyVoltageScan { _voltages: voltages })
+            },
+            _ => Err(exceptions::PyValueError::new_err(
+                "order must be one of 'linear', 'random' or 'bidirectional'"))
+        }
+    }
+
+    /// voltages(self, /)
+    /// --
+    ///
+    /// :return: The materialized voltage points of this scan, in their current order
+    /// :rtype: A numpy f32 array
+    fn voltages<'py>(&self, py: Python<'py>) -> &'py PyArray<f32, Ix1> {
+        Array::from(self._voltages.clone()).into_pyarray(py)
+    }
+
+    fn __len__(&self) -> usize {
+        self._voltages.len()
+    }
+}
+
+/// Simple forward iterator over a materialized scan's voltage points, returned by
+/// ``__iter__`` on :class:`~pyarc2.RangeScan` and :class:`~pyarc2.CenterScan`.
+#[pyclass(module="pyarc2")]
+/// Backs the `__iter__`/`__next__` protocol for RangeScan/CenterScan. Needs
+/// `PyRef` from pyo3::prelude in scope, same as PyReadStream below.
+struct PyF32Iter { _values: Vec<f32>, _idx: usize }
+
+#[pymethods]
+impl PyF32Iter {
+
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<f32> {
+        if slf._idx < slf._values.len() {
+            let v = slf._values[slf._idx];
+            slf._idx += 1;
+            Some(v)
+        } else {
+            None
+        }
+    }
+}
+
+/// RangeScan yields ``npoints`` evenly spaced setpoints from ``start`` to ``stop``
+/// inclusive, modeled on ARTIQ's scan objects. Feed it directly to pulse/ramp
+/// operations as a bias sequence, or materialize it with
+/// :meth:`~pyarc2.RangeScan.array`.
+///
+/// :param float start: First voltage of the scan
+/// :param float stop: Last voltage of the scan
+/// :param int npoints: Number of points; ``1`` returns just ``[start]``
+/// :param bool randomize: Permute the emitted order with a seeded PRNG so systematic
+///                        drift doesn't correlate with the sweep axis
+/// :param int seed: Seed for the PRNG; only used when ``randomize`` is ``True``
+#[pyclass(name="RangeScan", module="pyarc2")]
+#[derive(Clone)]
+struct PyRangeScan { _values: Vec<f32> }
+
+#[pymethods]
+impl PyRangeScan {
+
+    #[new]
+    fn new(start: f32, stop: f32, npoints: usize, randomize: Option<bool>, seed: Option<u64>)
+        -> PyResult<Self> {
+
+        if npoints == 0 {
+            return Err(exceptions::PyValueError::new_err("npoints must be at least 1"));
+        }
+
+        let mut values = if npoints == 1 {
+            vec![start]
+        } else {
+            let step = (stop - start) / (npoints - 1) as f32;
+            (0..npoints).map(|i| start + step * i as f32).collect()
+        };
+
+        if randomize.unwrap_or(false) {
+            let mut rng = Xorshift64::new(seed.unwrap_or(1));
+            rng.shuffle(&mut values);
+        }
+
+        Ok(PyRangeScan { _values: values })
+    }
+
+    /// array(self, /)
+    /// --
+    ///
+    /// :return: The materialized voltage points of this scan, in their current order
+    /// :rtype: A numpy f32 array
+    fn array<'py>(&self, py: Python<'py>) -> &'py PyArray<f32, Ix1> {
+        Array::from(self._values.clone()).into_pyarray(py)
+    }
+
+    fn __len__(&self) -> usize {
+        self._values.len()
+    }
+
+    fn __iter__(&self) -> PyF32Iter {
+        PyF32Iter { _values: self._values.clone(), _idx: 0 }
+    }
+}
+
+/// CenterScan yields points from ``center - span/2`` to ``center + span/2`` in
+/// increments of ``step``, modeled on ARTIQ's scan objects. The point count is
+/// ``floor(span/step) + 1``, always including ``center`` when the count is odd.
+///
+/// :param float center: The voltage the scan is centered on
+/// :param float span: The total width of the scan
+/// :param float step: Voltage increment; must be strictly positive. A ``step``
+///                    larger than ``span`` returns just ``[center]``
+/// :param bool randomize: Permute the emitted order with a seeded PRNG so systematic
+///                        drift doesn't correlate with the sweep axis
+/// :param int seed: Seed for the PRNG; only used when ``randomize`` is ``True``
+/// :raises ValueError: If ``step`` is zero or negative
+#[pyclass(name="CenterScan", module="pyarc2")]
+#[derive(Clone)]
+struct PyCenterScan { _values: Vec<f32> }
+
+#[pymethods]
+impl PyCenterScan {
+
+    #[new]
+    fn new(center: f32, span: f32, step: f32, randomize: Option<bool>, seed: Option<u64>)
+        -> PyResult<Self> {
+
+        if step <= 0.0 {
+            return Err(exceptions::PyValueError::new_err("step must be strictly positive"));
+        }
+
+        let mut values = if step > span {
+            vec![center]
+        } else {
+            let npoints = (span / step).floor() as usize + 1;
+            let half = (npoints - 1) as f32 / 2.0;
+            (0..npoints).map(|i| center + (i as f32 - half) * step).collect()
+        };
+
+        if randomize.unwrap_or(false) {
+            let mut rng = Xorshift64::new(seed.unwrap_or(1));
+            rng.shuffle(&mut values);
+        }
+
+        Ok(PyCenterScan { _values: values })
+    }
+
+    /// array(self, /)
+    /// --
+    ///
+    /// :return: The materialized voltage points of this scan, in their current order
+    /// :rtype: A numpy f32 array
+    fn array<'py>(&self, py: Python<'py>) -> &'py PyArray<f32, Ix1> {
+        Array::from(self._values.clone()).into_pyarray(py)
+    }
+
+    fn __len__(&self) -> usize {
+        self._values.len()
+    }
+
+    fn __iter__(&self) -> PyF32Iter {
+        PyF32Iter { _values: self._values.clone(), _idx: 0 }
+    }
+}
+
 /// Identifier for selecting auxiliary DAC functions. Typically used
 /// with :meth:`pyarc2.Instrument.config_aux_channels`.
 ///
@@ -482,7 +876,113 @@ impl From<&PyAuxDACFn> for AuxDACFn {
     }
 }
 
-/// Catch-all exception for low-level ArC2 errors
+/// Broad category of a low-level ArC2 error, mirrored from the
+/// ``LLArC2Error`` discriminant. Exposed as the ``category`` attribute of the
+/// error object carried as the first argument (``err.args[0]``) of a raised
+/// :class:`~pyarc2.ArC2Error`, so callers can branch on the failure class
+/// without string-matching ``str(err)``.
+///
+/// :var FPGAComm: FPGA communication error
+/// :var MemoryAccess: Memory access error
+/// :var InvalidDeviceID: Invalid device ID
+/// :var RampConsistency: Inconsistent ramp parameters
+/// :var OutputBuffer: Output buffer access error
+#[pyclass(name="ErrorCategory", module="pyarc2")]
+#[derive(Clone)]
+struct PyErrorCategory { _inner: ErrCategory }
+
+#[allow(non_snake_case)]
+#[pymethods]
+impl PyErrorCategory {
+
+    #[classattr]
+    fn FPGAComm() -> PyErrorCategory {
+        PyErrorCategory { _inner: ErrCategory::FPGAComm }
+    }
+
+    #[classattr]
+    fn MemoryAccess() -> PyErrorCategory {
+        PyErrorCategory { _inner: ErrCategory::MemoryAccess }
+    }
+
+    #[classattr]
+    fn InvalidDeviceID() -> PyErrorCategory {
+        PyErrorCategory { _inner: ErrCategory::InvalidDeviceID }
+    }
+
+    #[classattr]
+    fn RampConsistency() -> PyErrorCategory {
+        PyErrorCategory { _inner: ErrCategory::RampConsistency }
+    }
+
+    #[classattr]
+    fn OutputBuffer() -> PyErrorCategory {
+        PyErrorCategory { _inner: ErrCategory::OutputBuffer }
+    }
+
+    fn __str__(&self) -> &'static str {
+        self._inner.as_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ErrorCategory<{}>", self._inner.as_str())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ErrCategory {
+    FPGAComm,
+    MemoryAccess,
+    InvalidDeviceID,
+    RampConsistency,
+    OutputBuffer
+}
+
+impl ErrCategory {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ErrCategory::FPGAComm => "FPGAComm",
+            ErrCategory::MemoryAccess => "MemoryAccess",
+            ErrCategory::InvalidDeviceID => "InvalidDeviceID",
+            ErrCategory::RampConsistency => "RampConsistency",
+            ErrCategory::OutputBuffer => "OutputBuffer"
+        }
+    }
+
+    fn of(err: &LLArC2Error) -> ErrCategory {
+        match err {
+            LLArC2Error::FPGAError(..) => ErrCategory::FPGAComm,
+            LLArC2Error::MemoryError(..) => ErrCategory::MemoryAccess,
+            LLArC2Error::InvalidDeviceIDError(..) => ErrCategory::InvalidDeviceID,
+            LLArC2Error::RampError(..) => ErrCategory::RampConsistency,
+            LLArC2Error::OutputBufferError(..) => ErrCategory::OutputBuffer
+        }
+    }
+
+    /// A synthetic, sequential id for this category. These are assigned in this
+    /// binding and are not libarc2 firmware/error codes; they only exist so callers
+    /// have a stable integer to log or compare without matching on `as_str()`.
+    fn code(&self) -> u32 {
+        match self {
+            ErrCategory::FPGAComm => 1,
+            ErrCategory::MemoryAccess => 2,
+            ErrCategory::InvalidDeviceID => 3,
+            ErrCategory::RampConsistency => 4,
+            ErrCategory::OutputBuffer => 5
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(self, ErrCategory::FPGAComm)
+    }
+}
+
+/// Catch-all exception for low-level ArC2 errors. Subclasses
+/// :class:`~pyarc2.FPGACommError`, :class:`~pyarc2.MemoryAccessError`,
+/// :class:`~pyarc2.InvalidDeviceIDError`, :class:`~pyarc2.RampConsistencyError`
+/// and :class:`~pyarc2.OutputBufferError` narrow this down to the specific
+/// failure category, so prefer catching those over this base class when the
+/// distinction matters.
 /// --
 #[pyclass(name="ArC2Error", module="pyarc2")]
 struct PyArC2Error { _inner: LLArC2Error }
@@ -490,6 +990,48 @@ struct PyArC2Error { _inner: LLArC2Error }
 #[pymethods]
 impl PyArC2Error {
 
+    /// The broad failure category of this error.
+    ///
+    /// :rtype: pyarc2.ErrorCategory
+    #[getter]
+    fn category(&self) -> PyErrorCategory {
+        PyErrorCategory { _inner: ErrCategory::of(&self._inner) }
+    }
+
+    /// The offending device ID, only set when
+    /// ``category == ErrorCategory.InvalidDeviceID``.
+    ///
+    /// :raises AttributeError: When this error is not an invalid device ID error
+    #[getter]
+    fn device_id(&self) -> PyResult<i32> {
+        match self._inner {
+            LLArC2Error::InvalidDeviceIDError(id) => Ok(id),
+            _ => Err(exceptions::PyAttributeError::new_err(
+                "No device id associated with this error"))
+        }
+    }
+
+    /// Stable numeric identifier of this error's :attr:`category`. This is a
+    /// synthetic id assigned by this binding (``FPGAComm`` is always ``1``,
+    /// ``MemoryAccess`` always ``2``, and so on) so callers can log or compare
+    /// categories as an integer; it does **not** correspond to any libarc2
+    /// firmware or host error code. ``LLArC2Error`` doesn't carry one for us
+    /// to surface here: its variants hold a device id or a message string,
+    /// not a numeric code, so there is nothing more precise to expose.
+    #[getter]
+    fn code(&self) -> u32 {
+        ErrCategory::of(&self._inner).code()
+    }
+
+    /// Whether this failure is likely transient (eg. an FPGA communication timeout)
+    /// and therefore worth retrying, as opposed to a fatal parameter or device error.
+    /// :meth:`~pyarc2.find_ids` and connection code can use this to distinguish
+    /// retryable from fatal conditions without matching on :attr:`category` directly.
+    #[getter]
+    fn is_retryable(&self) -> bool {
+        ErrCategory::of(&self._inner).is_retryable()
+    }
+
     fn __str__(&self) -> String {
         let inner = &self._inner;
         format!("{}", inner)
@@ -516,29 +1058,196 @@ create_exception!(pyarc2, ArC2Error, exceptions::PyException,
     (3) Invalid device ID, (4) Inconsistent ramp errors \
     and (5) Output buffer access errors");
 
+create_exception!(pyarc2, FPGACommError, ArC2Error,
+    "Raised when communication with the FPGA fails");
+create_exception!(pyarc2, MemoryAccessError, ArC2Error,
+    "Raised on an invalid memory access on the instrument");
+create_exception!(pyarc2, InvalidDeviceIDError, ArC2Error,
+    "Raised when a device ID does not correspond to a connected instrument. \
+    Carries the offending ID in the ``device_id`` attribute of the underlying error");
+create_exception!(pyarc2, RampConsistencyError, ArC2Error,
+    "Raised when a ramp operation is requested with inconsistent parameters");
+create_exception!(pyarc2, OutputBufferError, ArC2Error,
+    "Raised on an invalid access of the output buffer");
+
 impl ArC2Error {
     pub fn new_exception(err: LLArC2Error) -> PyErr {
-        ArC2Error::new_err(PyArC2Error { _inner: err })
+        let category = ErrCategory::of(&err);
+        let payload = PyArC2Error { _inner: err };
+
+        match category {
+            ErrCategory::FPGAComm => FPGACommError::new_err(payload),
+            ErrCategory::MemoryAccess => MemoryAccessError::new_err(payload),
+            ErrCategory::InvalidDeviceID => InvalidDeviceIDError::new_err(payload),
+            ErrCategory::RampConsistency => RampConsistencyError::new_err(payload),
+            ErrCategory::OutputBuffer => OutputBufferError::new_err(payload)
+        }
+    }
+}
+
+/// A chunked, compressed HDF5 sink for data drained from ArC2's internal long-operation
+/// buffer, used by :meth:`pyarc2.Instrument.stream_to`. Records are appended to a
+/// resizable dataset so a run of unbounded length streams straight to disk without ever
+/// materializing the full array in memory.
+#[pyclass(name="DataSink", module="pyarc2")]
+struct PyDataSink {
+    _file: hdf5::File,
+    _dataset: hdf5::Dataset,
+    _rows: usize
+}
+
+#[pymethods]
+impl PyDataSink {
+
+    /// DataSink(path, width, compression, /)
+    /// --
+    ///
+    /// Create an HDF5 file at ``path`` (truncating any existing file) and prepare a resizable,
+    /// chunked ``data`` dataset of row width ``width`` to append records to.
+    ///
+    /// :param str path: Destination HDF5 file
+    /// :param int width: Number of columns (channels) per record
+    /// :param str compression: One of ``gzip``, ``lzf`` or ``none``
+    /// :raises ValueError: If ``compression`` is not a recognized filter
+    #[new]
+    fn new(path: &str, width: usize, compression: &str) -> PyResult<Self> {
+
+        let file = hdf5::File::create(path)
+            .map_err(|e| exceptions::PyIOError::new_err(e.to_string()))?;
+
+        let builder = file.new_dataset::<f32>()
+            .shape((0.., width))
+            .chunk((1024, width));
+
+        let builder = match compression {
+            "gzip" => builder.deflate(4),
+            "lzf" => builder.lzf(),
+            "none" => builder,
+            _ => return Err(exceptions::PyValueError::new_err(
+                "compression must be one of 'gzip', 'lzf' or 'none'"))
+        };
+
+        let dataset = builder.create("data")
+            .map_err(|e| exceptions::PyIOError::new_err(e.to_string()))?;
+
+        Ok(PyDataSink { _file: file, _dataset: dataset, _rows: 0 })
+    }
+
+    /// set_meta(self, key, value, /)
+    /// --
+    ///
+    /// Attach a scalar string attribute to the dataset, for example the channel mask,
+    /// :class:`~pyarc2.DataMode`, :class:`~pyarc2.ReadType`, a timestamp or the firmware
+    /// :data:`pyarc2.LIBARC2_VERSION`.
+    ///
+    /// :param str key: Attribute name
+    /// :param str value: Attribute value
+    fn set_meta(&self, key: &str, value: &str) -> PyResult<()> {
+
+        let attr = self._dataset.new_attr::<hdf5::types::VarLenUnicode>().create(key)
+            .map_err(|e| exceptions::PyIOError::new_err(e.to_string()))?;
+        let value: hdf5::types::VarLenUnicode = value.parse().unwrap();
+
+        attr.write_scalar(&value)
+            .map_err(|e| exceptions::PyIOError::new_err(e.to_string()))
+    }
+
+    /// append(self, rows, /)
+    /// --
+    ///
+    /// Append ``rows`` (an ``(N, width)`` array) to the dataset, resizing it to make
+    /// room first.
+    ///
+    /// :param rows: An ``(N, width)`` numpy f32 array
+    fn append(&mut self, rows: PyReadonlyArray<f32, Ix2>) -> PyResult<()> {
+
+        let data = rows.as_array();
+        let nrows = data.shape()[0];
+        let width = data.shape()[1];
+
+        self._dataset.resize((self._rows + nrows, width))
+            .map_err(|e| exceptions::PyIOError::new_err(e.to_string()))?;
+        self._dataset.write_slice(&data, (self._rows..self._rows + nrows, ..))
+            .map_err(|e| exceptions::PyIOError::new_err(e.to_string()))?;
+
+        self._rows += nrows;
+        Ok(())
+    }
+
+    /// close(self, /)
+    /// --
+    ///
+    /// Flush and close the underlying HDF5 file.
+    fn close(&self) -> PyResult<()> {
+        self._file.close().map_err(|e| exceptions::PyIOError::new_err(e.to_string()))
     }
 }
 
 #[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
 #[pyclass(name="InstrumentLL", module="pyarc2", subclass)]
 pub struct PyInstrument {
-    _instrument: Instrument
+    _instrument: Arc<std::sync::Mutex<SyncInstrument>>,
+    _waveforms: std::collections::HashMap<usize, Vec<(f32, u64, bool)>>,
+    _elapsed_nanos: u128,
+    _subscription: Option<(Arc<AtomicBool>, std::thread::JoinHandle<()>)>
 }
 
+/// Wrapper that lets the background worker spawned by
+/// :meth:`~pyarc2.Instrument.subscribe` share the instrument with the main thread.
+/// ``Instrument`` itself is not ``Send`` (it wraps non-thread-safe FFI handles), so
+/// access is always mediated through the surrounding ``Mutex``: every
+/// :class:`~pyarc2.Instrument` method, the subscription worker and ``unsubscribe``
+/// all lock it before touching the device, which rules out the concurrent access
+/// that an unsynchronized raw pointer would allow.
+struct SyncInstrument(Instrument);
+unsafe impl Send for SyncInstrument {}
+
+impl std::ops::Deref for SyncInstrument {
+    type Target = Instrument;
+    fn deref(&self) -> &Instrument { &self.0 }
+}
+
+impl std::ops::DerefMut for SyncInstrument {
+    fn deref_mut(&mut self) -> &mut Instrument { &mut self.0 }
+}
+
+/// Hard DAC voltage ceiling enforced by :meth:`~pyarc2.Instrument.load_waveform` and
+/// reported by :meth:`~pyarc2.Instrument.info`.
+const DAC_VOLTAGE_MIN: f32 = -10.0;
+const DAC_VOLTAGE_MAX: f32 = 10.0;
+const ADC_RESOLUTION_BITS: u8 = 18;
+const ADC_LSB_VOLTS: f32 = (DAC_VOLTAGE_MAX - DAC_VOLTAGE_MIN) / 262144.0; // 2^18
+const NUM_CHANNELS: usize = 64;
+const BUFFER_CAPACITY_RECORDS: usize = 2_000_000;
+const MIN_PULSE_WIDTH_NANOS: u64 = 10;
+const TIMING_GRANULARITY_NANOS: u64 = 10;
+
 #[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
 impl PyInstrument {
 
-    /// Returns a reference to the underlying Instrument
-    pub fn inner(&self) -> &Instrument {
-        &self._instrument
+    /// Returns a lock guard granting access to the underlying Instrument
+    pub fn inner(&self) -> std::sync::MutexGuard<SyncInstrument> {
+        self._instrument.lock().unwrap()
     }
 
-    /// Returns a mutable reference to the underlying Instrument
-    pub fn inner_mut(&mut self) -> &mut Instrument {
-        &mut self._instrument
+    /// Returns a lock guard granting mutable access to the underlying Instrument
+    pub fn inner_mut(&mut self) -> std::sync::MutexGuard<SyncInstrument> {
+        self._instrument.lock().unwrap()
+    }
+}
+
+/// Ensures a background worker started by :meth:`~pyarc2.Instrument.subscribe` is
+/// always stopped and joined, even if the Python object is dropped without an
+/// explicit call to :meth:`~pyarc2.Instrument.unsubscribe`.
+#[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
+impl Drop for PyInstrument {
+    fn drop(&mut self) {
+        if let Some((stop, handle)) = self._subscription.take() {
+            stop.store(true, Ordering::Relaxed);
+            // Same deadlock hazard as unsubscribe(): release the GIL around the
+            // join in case the worker is parked in Python::with_gil.
+            Python::with_gil(|py| py.allow_threads(|| { let _ = handle.join(); }));
+        }
     }
 }
 
@@ -549,7 +1258,12 @@ impl PyInstrument {
     #[new(name="InstrumentLL")]
     fn new(id: i32, fw: &str) -> PyResult<Self> {
         match Instrument::open_with_fw(id, fw, true) {
-            Ok(instr) => Ok(PyInstrument { _instrument: instr }),
+            Ok(instr) => Ok(PyInstrument {
+                _instrument: Arc::new(std::sync::Mutex::new(SyncInstrument(instr))),
+                _waveforms: std::collections::HashMap::new(),
+                _elapsed_nanos: 0,
+                _subscription: None
+            }),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
     }
@@ -559,7 +1273,91 @@ impl PyInstrument {
     ///
     /// Insert a delay of ``nanos`` nanoseconds in the command buffer.
     fn delay<'py>(mut slf: PyRefMut<'py, Self>, nanos: u128) -> PyResult<PyRefMut<'py, Self>> {
-        match slf._instrument.add_delay(nanos) {
+        match slf._instrument.lock().unwrap().add_delay(nanos) {
+            Ok(_) => {
+                slf._elapsed_nanos += nanos;
+                Ok(slf)
+            },
+            Err(err) => Err(ArC2Error::new_exception(err))
+        }
+    }
+
+    /// at(self, nanos, /)
+    /// --
+    ///
+    /// Move the timeline cursor to an absolute ``nanos`` offset from the start of the
+    /// command buffer, inserting a delay to cover the gap if the cursor isn't there
+    /// already. The next queued ``pulse_*``/``read_*`` instruction will therefore land at
+    /// precisely ``nanos``, letting buffered operations be aligned to an externally
+    /// clocked experiment instead of relying on host-side :meth:`~pyarc2.Instrument.wait`
+    /// polling.
+    ///
+    /// Only :meth:`delay`, :meth:`at` and :meth:`after` themselves move the cursor;
+    /// ``pulse_*``/``read_*``/``generate_ramp*`` calls queue their own timed buffer
+    /// entries without advancing it. Interleaving those with ``at``/``after`` will
+    /// therefore schedule relative to a cursor that doesn't reflect the time those
+    /// calls actually occupy in the buffer.
+    ///
+    /// :param int nanos: Absolute offset, in nanoseconds, from the start of the buffer
+    /// :raises ~pyarc2.ArC2Error: If ``nanos`` is earlier than the current cursor position
+    fn at<'py>(mut slf: PyRefMut<'py, Self>, nanos: u128) -> PyResult<PyRefMut<'py, Self>> {
+
+        if nanos < slf._elapsed_nanos {
+            return Err(ArC2Error::new_exception(
+                LLArC2Error::RampError(format!(
+                    "Cannot schedule at {} ns: timeline cursor is already at {} ns",
+                    nanos, slf._elapsed_nanos))));
+        }
+
+        let gap = nanos - slf._elapsed_nanos;
+
+        if gap > 0 {
+            if let Err(err) = slf._instrument.lock().unwrap().add_delay(gap) {
+                return Err(ArC2Error::new_exception(err));
+            }
+        }
+
+        slf._elapsed_nanos = nanos;
+        Ok(slf)
+    }
+
+    /// after(self, nanos, /)
+    /// --
+    ///
+    /// Move the timeline cursor forward by ``nanos`` relative to its current position.
+    /// Equivalent to ``self.at(self.elapsed() + nanos)``.
+    ///
+    /// :param int nanos: Relative offset, in nanoseconds, to advance the cursor by
+    fn after<'py>(mut slf: PyRefMut<'py, Self>, nanos: u128) -> PyResult<PyRefMut<'py, Self>> {
+        let target = slf._elapsed_nanos + nanos;
+        PyInstrument::at(slf, target)
+    }
+
+    /// elapsed(self, /)
+    /// --
+    ///
+    /// :return: The current position of the timeline cursor, in nanoseconds from the
+    ///          start of the command buffer
+    /// :rtype: int
+    fn elapsed(&self) -> u128 {
+        self._elapsed_nanos
+    }
+
+    /// wait_trigger(self, channel_mask, /)
+    /// --
+    ///
+    /// Stall execution of the command buffer until a digital input edge arrives on any
+    /// of the channels in ``channel_mask``. This lets ArC2 pulsing be interleaved
+    /// deterministically with an externally clocked experiment.
+    ///
+    /// :param int channel_mask: A ``u32`` bitmask of the digital input channels to
+    ///                          wait on
+    fn wait_trigger<'py>(mut slf: PyRefMut<'py, Self>, channel_mask: u32)
+        -> PyResult<PyRefMut<'py, Self>> {
+
+        let mask = IOMask::from_vals(&[channel_mask]);
+
+        match slf._instrument.lock().unwrap().wait_for_trigger(&mask) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -570,7 +1368,7 @@ impl PyInstrument {
     ///
     /// Ground all channels and revert them to arbitrary voltage operation.
     fn ground_all<'py>(mut slf: PyRefMut<'py, Self>) -> PyResult<PyRefMut<'py, Self>> {
-        match slf._instrument.ground_all() {
+        match slf._instrument.lock().unwrap().ground_all() {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -581,7 +1379,7 @@ impl PyInstrument {
     ///
     /// Ground all channels maintaing current channel operating mode.
     fn ground_all_fast<'py>(mut slf: PyRefMut<'py, Self>) -> PyResult<PyRefMut<'py, Self>> {
-        match slf._instrument.ground_all_fast() {
+        match slf._instrument.lock().unwrap().ground_all_fast() {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -599,7 +1397,7 @@ impl PyInstrument {
         -> PyResult<PyRefMut<'py, Self>> {
 
         let slice = chans.as_slice().unwrap();
-        match slf._instrument.connect_to_gnd(slice) {
+        match slf._instrument.lock().unwrap().connect_to_gnd(slice) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -610,7 +1408,7 @@ impl PyInstrument {
     ///
     /// Disconnect all channels.
     fn float_all<'py>(mut slf: PyRefMut<'py, Self>) -> PyResult<PyRefMut<'py, Self>> {
-        match slf._instrument.float_all() {
+        match slf._instrument.lock().unwrap().float_all() {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -633,7 +1431,7 @@ impl PyInstrument {
     fn open_channels<'py>(mut slf: PyRefMut<'py, Self>, channels: Vec<usize>) ->
         PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.open_channels(&channels) {
+        match slf._instrument.lock().unwrap().open_channels(&channels) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -651,7 +1449,7 @@ impl PyInstrument {
     fn config_channels<'py>(mut slf: PyRefMut<'py, Self>, input: Vec<(u16, f32)>, base: Option<f32>)
         -> PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.config_channels(&input, base) {
+        match slf._instrument.lock().unwrap().config_channels(&input, base) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -676,7 +1474,7 @@ impl PyInstrument {
                 (dac, item.1)
             }).collect();
 
-        match slf._instrument.config_aux_channels(&rust_input) {
+        match slf._instrument.lock().unwrap().config_aux_channels(&rust_input) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -708,7 +1506,7 @@ impl PyInstrument {
     fn config_selectors<'py>(mut slf: PyRefMut<'py, Self>, selectors: Vec<usize>)
         -> PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.config_selectors(&selectors) {
+        match slf._instrument.lock().unwrap().config_selectors(&selectors) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -728,7 +1526,7 @@ impl PyInstrument {
     /// :return: The current between the specified crosspoints at ``vread``
     /// :rtype: float
     fn read_one(&mut self, low: usize, high: usize, vread: f32) -> f32 {
-        self._instrument.read_one(low, high, vread).unwrap()
+        self._instrument.lock().unwrap().read_one(low, high, vread).unwrap()
     }
 
     /// read_slice(self, chan, vread, /)
@@ -745,7 +1543,7 @@ impl PyInstrument {
     ///          at ``chan``
     /// :rtype: A numpy f32 array
     fn read_slice<'py>(&mut self, py: Python<'py>, chan: usize, vread: f32) -> &'py PyArray<f32, Ix1> {
-        let array = Array::from(self._instrument.read_slice(chan, vread).unwrap());
+        let array = Array::from(self._instrument.lock().unwrap().read_slice(chan, vread).unwrap());
         array.into_pyarray(py)
     }
 
@@ -768,7 +1566,7 @@ impl PyInstrument {
         mask: PyReadonlyArray<usize, Ix1>, vread: f32) -> &'py PyArray<f32, Ix1> {
 
         let slice = mask.as_slice().unwrap();
-        let array = Array::from(self._instrument.read_slice_masked(chan, slice, vread).unwrap());
+        let array = Array::from(self._instrument.lock().unwrap().read_slice_masked(chan, slice, vread).unwrap());
 
         array.into_pyarray(py)
     }
@@ -787,12 +1585,71 @@ impl PyInstrument {
     /// :rtype: A numpy (2, 2) f32 ndarray
     fn read_all<'py>(&mut self, py: Python<'py>, vread: f32, order: PyBiasOrder) -> &'py PyArray<f32, Ix2> {
 
-        let data = self._instrument.read_all(vread, order.into()).unwrap();
+        let data = self._instrument.lock().unwrap().read_all(vread, order.into()).unwrap();
         let array = Array::from_shape_vec((32, 32), data).unwrap();
 
         array.into_pyarray(py)
     }
 
+    /// read_all_classified(self, vread, order, thresholds, /)
+    /// --
+    ///
+    /// Perform the same acquisition as :meth:`~pyarc2.Instrument.read_all` but classify
+    /// each of the 1024 crosspoint currents into a discrete conductance state using the
+    /// caller-supplied threshold edges, instead of returning raw floats. With a single
+    /// threshold this yields a HRS/LRS classification; with ``N`` thresholds it returns
+    /// ``N + 1`` level indices. Unselected/failed (``NaN``) crosspoints are mapped to the
+    /// reserved sentinel level ``255`` so downstream analysis can distinguish them from
+    /// valid states.
+    ///
+    /// :param float vread: The read-out voltage
+    /// :param order: A variant of :class:`pyarc2.BiasOrder` denoting which rows are
+    ///              biased during read-out
+    /// :param thresholds: The conductance level edges; need not be pre-sorted
+    /// :return: A 32×32 ``uint8`` array of level indices, and, when exactly one threshold
+    ///          is supplied, a companion bit-packed array (1 bit per crosspoint, row-major)
+    ///          to minimize transfer size for large retention/endurance sweeps
+    /// :rtype: (numpy.ndarray, Optional[numpy.ndarray])
+    fn read_all_classified<'py>(&mut self, py: Python<'py>, vread: f32, order: PyBiasOrder,
+        thresholds: Vec<f32>) -> PyResult<(&'py PyArray<u8, Ix2>, Option<&'py PyArray<u8, Ix1>>)> {
+
+        const SENTINEL: u8 = 255;
+
+        let data = match self._instrument.lock().unwrap().read_all(vread, order.into()) {
+            Ok(d) => d,
+            Err(err) => return Err(ArC2Error::new_exception(err))
+        };
+
+        let mut edges = thresholds.clone();
+        edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let levels: Vec<u8> = data.iter().map(|current| {
+            if current.is_nan() {
+                SENTINEL
+            } else {
+                edges.iter().position(|edge| current < edge)
+                    .map(|idx| idx as u8)
+                    .unwrap_or(edges.len() as u8)
+            }
+        }).collect();
+
+        let packed = if edges.len() == 1 {
+            let mut bytes = vec![0u8; (levels.len() + 7) / 8];
+            for (idx, level) in levels.iter().enumerate() {
+                if *level == 1 {
+                    bytes[idx / 8] |= 1 << (idx % 8);
+                }
+            }
+            Some(Array::from(bytes).into_pyarray(py))
+        } else {
+            None
+        };
+
+        let level_array = Array::from_shape_vec((32, 32), levels).unwrap().into_pyarray(py);
+
+        Ok((level_array, packed))
+    }
+
     /// read_slice_open(self, highs, ground_after, /)
     /// --
     ///
@@ -812,7 +1669,7 @@ impl PyInstrument {
         let slice = highs.as_slice().unwrap();
         let ground = ground_after.unwrap_or(true);
 
-        self._instrument.read_slice_open(slice, ground).unwrap().into_pyarray(py)
+        self._instrument.lock().unwrap().read_slice_open(slice, ground).unwrap().into_pyarray(py)
     }
 
     /// pulse_one(self, low, high, voltage, nanos, /)
@@ -828,7 +1685,7 @@ impl PyInstrument {
     fn pulse_one<'py>(mut slf: PyRefMut<'py, Self>, low: usize, high: usize, voltage: f32, nanos: u128)
         -> PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.pulse_one(low, high, voltage, nanos) {
+        match slf._instrument.lock().unwrap().pulse_one(low, high, voltage, nanos) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -846,7 +1703,7 @@ impl PyInstrument {
     fn pulse_slice<'py>(mut slf: PyRefMut<'py, Self>, chan: usize, voltage: f32, nanos: u128)
         -> PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.pulse_slice(chan, voltage, nanos) {
+        match slf._instrument.lock().unwrap().pulse_slice(chan, voltage, nanos) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -870,7 +1727,7 @@ impl PyInstrument {
 
         let actual_mask = mask.as_slice().unwrap();
 
-        match slf._instrument.pulse_slice_masked(chan, actual_mask, voltage, nanos) {
+        match slf._instrument.lock().unwrap().pulse_slice_masked(chan, actual_mask, voltage, nanos) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -921,12 +1778,61 @@ impl PyInstrument {
 
         let actual_cl_nanos: [Option<u128>; 8] = cl_nanos[0..8].try_into()?;
 
-        match slf._instrument.pulse_slice_fast_open(&chans, &actual_cl_nanos, preset_state) {
+        match slf._instrument.lock().unwrap().pulse_slice_fast_open(&chans, &actual_cl_nanos, preset_state) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
     }
 
+    /// generate_waveform(self, chan, samples, /)
+    /// --
+    ///
+    /// Play an arbitrary piecewise-constant waveform on ``chan`` through the high speed
+    /// drivers, one segment after another. ``samples`` is a list of ``(voltage, nanos)``
+    /// pairs; each segment is held steady at its own voltage for its ``nanos`` duration
+    /// before jumping straight to the next segment's voltage, built on top of
+    /// :meth:`~pyarc2.Instrument.pulse_slice_fast_open`. This lets callers synthesize
+    /// staircase sweeps, triangular potentiation ramps or custom read-pulse-read
+    /// envelopes without chaining dozens of
+    /// :meth:`~pyarc2.Instrument.config_channels`/:meth:`~pyarc2.Instrument.delay` calls.
+    ///
+    /// :param int chan: The channel to play the waveform on
+    /// :param samples: A list of ``(voltage, nanos)`` pairs describing the waveform
+    /// :raises ~pyarc2.ArC2Error: If a segment exceeds the 500 ms per-segment high speed
+    ///                           driver limitation
+    fn generate_waveform<'py>(mut slf: PyRefMut<'py, Self>, chan: usize,
+        samples: Vec<(f32, u128)>) -> PyResult<PyRefMut<'py, Self>> {
+
+        const MAX_SEGMENT_NANOS: u128 = 500_000_000;
+
+        let cluster = chan / 8;
+
+        for (voltage, nanos) in samples.iter() {
+
+            if *nanos > MAX_SEGMENT_NANOS {
+                return Err(ArC2Error::new_exception(
+                    LLArC2Error::OutputBufferError(format!(
+                        "Segment of {} ns exceeds the 500 ms high speed driver limit", nanos))));
+            }
+
+            let mut cl_nanos: [Option<u128>; 8] = [None; 8];
+            cl_nanos[cluster] = Some(*nanos);
+
+            // Hold each segment at its own voltage for the full duration (`normal`
+            // equal to `voltage`) so consecutive segments jump straight from one
+            // level to the next instead of dipping back to the previous segment's
+            // voltage in between, which is what actually produces the piecewise-
+            // constant staircase.
+            let chans = vec![(chan, *voltage, *voltage)];
+
+            if let Err(err) = slf._instrument.lock().unwrap().pulse_slice_fast_open(&chans, &cl_nanos, false) {
+                return Err(ArC2Error::new_exception(err));
+            }
+        }
+
+        Ok(slf)
+    }
+
     /// pulse_all(self, voltage, nanos, order, /)
     /// --
     ///
@@ -938,7 +1844,7 @@ impl PyInstrument {
     fn pulse_all<'py>(mut slf: PyRefMut<'py, Self>, voltage: f32, nanos: u128, order: PyBiasOrder)
         -> PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.pulse_all(voltage, nanos, order.into()) {
+        match slf._instrument.lock().unwrap().pulse_all(voltage, nanos, order.into()) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -959,7 +1865,7 @@ impl PyInstrument {
     ///          a ``vpulse`` pulse of ``nanos`` duration has been applied
     /// :rtype: float
     fn pulseread_one(&mut self, low: usize, high: usize, vpulse: f32, nanos: u128, vread: f32) -> f32 {
-        self._instrument.pulseread_one(low, high, vpulse, nanos, vread).unwrap()
+        self._instrument.lock().unwrap().pulseread_one(low, high, vpulse, nanos, vread).unwrap()
     }
 
     /// pulseread_slice(self, chan, vpulse, nanos, vread, /)
@@ -980,7 +1886,7 @@ impl PyInstrument {
     fn pulseread_slice<'py>(&mut self, py: Python<'py>, chan: usize, vpulse: f32,
         nanos: u128, vread: f32) -> &'py PyArray<f32, Ix1> {
 
-        let data = self._instrument.pulseread_slice(chan, vpulse, nanos, vread).unwrap();
+        let data = self._instrument.lock().unwrap().pulseread_slice(chan, vpulse, nanos, vread).unwrap();
         Array::from(data).into_pyarray(py)
 
     }
@@ -1006,7 +1912,7 @@ impl PyInstrument {
         vread: f32) -> &'py PyArray<f32, Ix1> {
 
         let slice = mask.as_slice().unwrap();
-        let data = self._instrument.pulseread_slice_masked(chan, slice, vpulse, nanos, vread)
+        let data = self._instrument.lock().unwrap().pulseread_slice_masked(chan, slice, vpulse, nanos, vread)
             .unwrap();
         Array::from(data).into_pyarray(py)
     }
@@ -1029,13 +1935,218 @@ impl PyInstrument {
     fn pulseread_all<'py>(&mut self, py: Python<'py>, vpulse: f32, nanos: u128,
         vread: f32, order: PyBiasOrder) -> &'py PyArray<f32, Ix2> {
 
-        let data = self._instrument.pulseread_all(vpulse, nanos, vread, order.into())
+        let data = self._instrument.lock().unwrap().pulseread_all(vpulse, nanos, vread, order.into())
             .unwrap();
 
         Array::from_shape_vec((32, 32), data).unwrap().into_pyarray(py)
 
     }
 
+    /// program_to_resistance(self, low, high, target_r, tolerance, vread, v_start, v_step,
+    /// pulse_width, max_pulses, v_ceil, polarity_bias, /)
+    /// --
+    ///
+    /// Drive the crosspoint defined by ``low``/``high`` towards ``target_r`` Ohms with a
+    /// read-verify-write servo loop that runs entirely on the Rust side, bypassing the
+    /// per-pulse round-trip to Python. Every iteration reads the current resistance at the
+    /// fixed ``vread`` and computes the relative error ``(r - target_r) / target_r``; the loop
+    /// stops successfully once ``abs(error) <= tolerance``. Otherwise a single pulse is applied
+    /// whose polarity follows the sign of the error (a SET pulse, signed by ``polarity_bias``,
+    /// lowers resistance; a RESET pulse raises it) at the current amplitude and ``pulse_width``.
+    /// If the error changes sign between two consecutive reads the pulse overshot, so the
+    /// amplitude and subsequent step are halved (the polarity is never tracked explicitly;
+    /// it simply follows the sign of ``error`` each iteration, which is what bisects back
+    /// towards the target); otherwise the amplitude grows by ``v_step`` up to the ``v_ceil``
+    /// ceiling. The routine never exceeds ``v_ceil``, always reads back at
+    /// ``vread`` rather than the write amplitude, and grounds ``low``/``high`` before returning,
+    /// even on early termination or error.
+    ///
+    /// :param int low: The low voltage channel (typ. grounded)
+    /// :param int high: The high voltage channel
+    /// :param float target_r: Target resistance in Ohms
+    /// :param float tolerance: Acceptable relative error, eg. ``0.1`` for ±10%
+    /// :param float vread: Read-out voltage used to estimate resistance; this is never
+    ///                     used as a write amplitude
+    /// :param float v_start: Initial pulse amplitude
+    /// :param float v_step: Initial amplitude increment
+    /// :param int pulse_width: Pulse width in nanoseconds
+    /// :param int max_pulses: Maximum number of pulses before giving up
+    /// :param float v_ceil: Maximum allowed pulse amplitude
+    /// :param float polarity_bias: Sign convention for the SET polarity, either ``1.0``
+    ///                             or ``-1.0``
+    /// :return: A tuple of ``(achieved resistance, pulses applied, converged)`` plus a
+    ///          ``(N, 2)`` numpy trace of the ``(voltage, resistance)`` pair applied/measured
+    ///          at every pulse
+    /// :rtype: (float, int, bool, numpy.ndarray)
+    fn program_to_resistance<'py>(&mut self, py: Python<'py>, low: usize, high: usize,
+        target_r: f32, tolerance: f32, vread: f32, v_start: f32, v_step: f32,
+        pulse_width: u128, max_pulses: usize, v_ceil: f32, polarity_bias: f32)
+        -> PyResult<(f32, usize, bool, &'py PyArray<f32, Ix2>)> {
+
+        let mut amplitude = v_start.abs().min(v_ceil);
+        let mut step = v_step.abs();
+        let polarity = polarity_bias.signum();
+        let mut trace: Vec<f32> = Vec::new();
+        let mut npulses = 0usize;
+        let mut converged = false;
+        let mut last_error: Option<f32> = None;
+
+        let mut resistance = match self._instrument.lock().unwrap().read_one(low, high, vread) {
+            Ok(current) => (vread / current).abs(),
+            Err(err) => {
+                let _ = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]);
+                return Err(ArC2Error::new_exception(err));
+            }
+        };
+
+        while npulses < max_pulses {
+
+            let error = (resistance - target_r) / target_r;
+
+            if error.abs() <= tolerance {
+                converged = true;
+                break;
+            }
+
+            if let Some(prev_error) = last_error {
+                if prev_error.signum() != error.signum() {
+                    step /= 2.0;
+                    amplitude = (amplitude / 2.0).max(step).min(v_ceil);
+                } else {
+                    amplitude = (amplitude + step).min(v_ceil);
+                }
+            }
+
+            // SET (lower resistance) is signed `polarity_bias`; RESET (raise
+            // resistance) is the opposite polarity. The error sign alone picks
+            // the direction, so an overshoot naturally reverses polarity on
+            // the next pulse without needing to track it separately.
+            let vpulse = if error > 0.0 {
+                polarity * amplitude
+            } else {
+                -polarity * amplitude
+            };
+
+            if let Err(err) = self._instrument.lock().unwrap().pulse_one(low, high, vpulse, pulse_width) {
+                let _ = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]);
+                return Err(ArC2Error::new_exception(err));
+            }
+
+            resistance = match self._instrument.lock().unwrap().read_one(low, high, vread) {
+                Ok(current) => (vread / current).abs(),
+                Err(err) => {
+                    let _ = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]);
+                    return Err(ArC2Error::new_exception(err));
+                }
+            };
+
+            trace.push(vpulse);
+            trace.push(resistance);
+            last_error = Some(error);
+            npulses += 1;
+        }
+
+        if let Err(err) = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]) {
+            return Err(ArC2Error::new_exception(err));
+        }
+
+        let nrows = npulses;
+        let array = Array::from_shape_vec((nrows, 2), trace).unwrap();
+
+        Ok((resistance, npulses, converged, array.into_pyarray(py)))
+    }
+
+    /// program_conductance(self, low, high, g_target, tol, vread, nanos, kp, v_min, v_max,
+    /// v_bump, max_attempts, /)
+    /// --
+    ///
+    /// Drive the crosspoint defined by ``low``/``high`` towards a target conductance
+    /// ``g_target`` with a proportional control loop that runs entirely on the Rust side.
+    /// Every attempt reads the current ``I`` at ``vread``, derives ``G = I / vread`` and the
+    /// error ``g_target - G``, and stops once ``abs(error) <= tol * g_target`` or after
+    /// ``max_attempts``. When ``G < g_target`` a potentiation (SET) pulse is applied,
+    /// otherwise an inhibition (RESET) pulse; the amplitude is updated proportionally as
+    /// ``v = clamp(v + kp * error / g_target, v_min, v_max)`` with a fixed pulse width
+    /// ``nanos``. To escape plateaus where the device does not respond, the amplitude is
+    /// bumped by ``v_bump`` whenever two consecutive reads differ by less than a
+    /// ``g_target``-relative dead-band.
+    ///
+    /// :param int low: The low voltage channel (typ. grounded)
+    /// :param int high: The high voltage channel
+    /// :param float g_target: Target conductance in Siemens
+    /// :param float tol: Acceptable relative error, eg. ``0.1`` for ±10%
+    /// :param float vread: Read-out voltage used to estimate conductance
+    /// :param int nanos: Pulse width in nanoseconds
+    /// :param float kp: Proportional gain of the amplitude update
+    /// :param float v_min: Minimum pulse amplitude
+    /// :param float v_max: Maximum pulse amplitude
+    /// :param float v_bump: Amplitude increment applied when the device plateaus
+    /// :param int max_attempts: Maximum number of pulses before giving up
+    /// :return: A numpy ``(N, 3)`` array logging ``(attempt, applied_voltage, measured_G)``
+    ///          for every attempt, where ``applied_voltage`` is the signed pulse that
+    ///          preceded the read (``0.0`` for the initial read)
+    /// :rtype: numpy.ndarray
+    fn program_conductance<'py>(&mut self, py: Python<'py>, low: usize, high: usize,
+        g_target: f32, tol: f32, vread: f32, nanos: u128, kp: f32, v_min: f32, v_max: f32,
+        v_bump: f32, max_attempts: usize) -> PyResult<&'py PyArray<f32, Ix2>> {
+
+        const DEAD_BAND: f32 = 1e-3;
+
+        let mut v = v_min.max(0.0);
+        let mut log: Vec<f32> = Vec::new();
+        let mut attempt = 0usize;
+        let mut last_g: Option<f32> = None;
+        let mut applied = 0.0f32;
+
+        loop {
+
+            let current = match self._instrument.lock().unwrap().read_one(low, high, vread) {
+                Ok(i) => i,
+                Err(err) => {
+                    let _ = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]);
+                    return Err(ArC2Error::new_exception(err));
+                }
+            };
+            let g = current / vread;
+            let error = g_target - g;
+
+            log.push(attempt as f32);
+            log.push(applied);
+            log.push(g);
+
+            if error.abs() <= tol * g_target || attempt >= max_attempts {
+                break;
+            }
+
+            if let Some(prev_g) = last_g {
+                if (g - prev_g).abs() < DEAD_BAND * g_target {
+                    v = (v + v_bump).min(v_max);
+                }
+            }
+
+            v = (v + kp * error / g_target).clamp(v_min, v_max);
+            last_g = Some(g);
+
+            // Potentiate (SET) when under target, inhibit (RESET) when over
+            let vpulse = if error > 0.0 { v } else { -v };
+            applied = vpulse;
+
+            if let Err(err) = self._instrument.lock().unwrap().pulse_one(low, high, vpulse, nanos) {
+                let _ = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]);
+                return Err(ArC2Error::new_exception(err));
+            }
+
+            attempt += 1;
+        }
+
+        if let Err(err) = self._instrument.lock().unwrap().connect_to_gnd(&[low, high]) {
+            return Err(ArC2Error::new_exception(err));
+        }
+
+        let nrows = log.len() / 3;
+        Ok(Array::from_shape_vec((nrows, 3), log).unwrap().into_pyarray(py))
+    }
+
     /// vread_channels(self, chans, averaging, /)
     /// --
     ///
@@ -1049,7 +2160,48 @@ impl PyInstrument {
     ///         in ascending order
     fn vread_channels(&mut self, chans: PyReadonlyArray<usize, Ix1>, averaging: bool) -> Vec<f32> {
         let slice = chans.as_slice().unwrap();
-        self._instrument.vread_channels(slice, averaging).unwrap()
+        self._instrument.lock().unwrap().vread_channels(slice, averaging).unwrap()
+    }
+
+    /// info(self, query, /)
+    /// --
+    ///
+    /// Look up a hardware capability or limit of the connected instrument, such as the
+    /// DAC voltage range or the long-operation buffer capacity. This lets scan
+    /// generators, range validators and GUIs size themselves to the connected device
+    /// and refuse out-of-range setpoints before they reach the firmware.
+    ///
+    /// :param query: A variant of :class:`pyarc2.InfoKey`
+    /// :return: The requested quantity, typed according to ``query``
+    fn info(&self, py: Python, query: PyInfoKey) -> PyObject {
+        match query._inner {
+            InfoKey::DACVoltageMin => DAC_VOLTAGE_MIN.into_py(py),
+            InfoKey::DACVoltageMax => DAC_VOLTAGE_MAX.into_py(py),
+            InfoKey::ADCResolution => ADC_RESOLUTION_BITS.into_py(py),
+            InfoKey::ADCLsb => ADC_LSB_VOLTS.into_py(py),
+            InfoKey::NumChannels => NUM_CHANNELS.into_py(py),
+            InfoKey::BufferCapacity => BUFFER_CAPACITY_RECORDS.into_py(py),
+            InfoKey::MinPulseWidth => MIN_PULSE_WIDTH_NANOS.into_py(py),
+            InfoKey::TimingGranularity => TIMING_GRANULARITY_NANOS.into_py(py)
+        }
+    }
+
+    /// capabilities(self, /)
+    /// --
+    ///
+    /// Convenience wrapper around :meth:`~pyarc2.Instrument.info` that returns the
+    /// whole set of hardware capabilities at once.
+    ///
+    /// :return: A dict keyed by the string name of each :class:`pyarc2.InfoKey` variant
+    /// :rtype: dict
+    fn capabilities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+
+        for key in InfoKey::all().iter() {
+            dict.set_item(key.as_str(), self.info(py, PyInfoKey { _inner: *key }))?;
+        }
+
+        Ok(dict)
     }
 
     /// execute(self, /)
@@ -1058,7 +2210,7 @@ impl PyInstrument {
     /// Write everything in the command buffer to the instrument. This will cause ArC2
     /// to start executing the instructions provided.
     fn execute<'py>(mut slf: PyRefMut<'py, Self>) -> PyResult<PyRefMut<'py, Self>> {
-        match slf._instrument.execute() {
+        match slf._instrument.lock().unwrap().execute() {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -1069,7 +2221,7 @@ impl PyInstrument {
     ///
     /// Returns `True` if the command buffer has not been consumed.
     fn busy(&self) -> bool {
-        self._instrument.busy()
+        self._instrument.lock().unwrap().busy()
     }
 
     /// wait(self, /)
@@ -1077,7 +2229,7 @@ impl PyInstrument {
     ///
     /// Block until the instrument has executed its command buffer.
     fn wait(&self) {
-        self._instrument.wait();
+        self._instrument.lock().unwrap().wait();
     }
 
     /// set_control_mode(self, mode, /)
@@ -1087,7 +2239,7 @@ impl PyInstrument {
     ///
     /// :param mode: A variant of :class:`pyarc2.ControlMode`
     fn set_control_mode<'py>(mut slf: PyRefMut<'py, Self>, mode: PyControlMode) -> PyResult<PyRefMut<'py, Self>> {
-        match slf._instrument.set_control_mode(mode.into()) {
+        match slf._instrument.lock().unwrap().set_control_mode(mode.into()) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -1104,12 +2256,48 @@ impl PyInstrument {
     fn set_logic<'py>(mut slf: PyRefMut<'py, Self>, mask: u32) -> PyResult<PyRefMut<'py, Self>> {
         let mask = IOMask::from_vals(&[mask]);
 
-        match slf._instrument.set_logic(&mask) {
+        match slf._instrument.lock().unwrap().set_logic(&mask) {
             Ok(_) => Ok(slf),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
     }
 
+    /// generate_logic_train(self, vectors, timings, /)
+    /// --
+    ///
+    /// Queue a buffered digital vector sequence: each ``u32`` output word in ``vectors``
+    /// is set on the digital I/Os and held for its matching duration (in nanoseconds)
+    /// in ``timings`` before moving on to the next vector, streaming the whole train out
+    /// back-to-back without a separate :meth:`~pyarc2.Instrument.execute` between steps.
+    /// This can drive external multiplexers, select lines or companion devices in
+    /// lockstep with array pulsing.
+    ///
+    /// :param vectors: A list (or numpy uint32 array) of digital output words
+    /// :param timings: A list (or numpy array) of per-step durations in nanoseconds;
+    ///                 must be the same length as ``vectors``
+    /// :raises ValueError: If ``vectors`` and ``timings`` don't have equal length
+    fn generate_logic_train<'py>(mut slf: PyRefMut<'py, Self>, vectors: Vec<u32>,
+        timings: Vec<u64>) -> PyResult<PyRefMut<'py, Self>> {
+
+        if vectors.len() != timings.len() {
+            return Err(exceptions::PyValueError::new_err(
+                "vectors and timings must have the same length"));
+        }
+
+        for (vector, nanos) in vectors.iter().zip(timings.iter()) {
+            let mask = IOMask::from_vals(&[*vector]);
+
+            if let Err(err) = slf._instrument.lock().unwrap().set_logic(&mask) {
+                return Err(ArC2Error::new_exception(err));
+            }
+            if let Err(err) = slf._instrument.lock().unwrap().add_delay(*nanos as u128) {
+                return Err(ArC2Error::new_exception(err));
+            }
+        }
+
+        Ok(slf)
+    }
+
     /// currents_from_address(self, addr, channels, /)
     /// --
     ///
@@ -1126,7 +2314,7 @@ impl PyInstrument {
     fn currents_from_address<'py>(&self, py: Python<'py>, addr: u32,
         chans: PyReadonlyArray<usize, Ix1>) -> PyResult<&'py PyArray<f32, Ix1>> {
 
-        match self._instrument.currents_from_address(addr, chans.as_slice().unwrap()) {
+        match self._instrument.lock().unwrap().currents_from_address(addr, chans.as_slice().unwrap()) {
             Ok(result) => Ok(Array::from(result).into_pyarray(py)),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -1142,7 +2330,7 @@ impl PyInstrument {
     /// :return: An array with the currents of all wordline-corresponding channels
     /// :rtype: A numpy f32 array
     fn word_currents_from_address<'py>(&self, py: Python<'py>, addr: u32) -> PyResult<&'py PyArray<f32, Ix1>> {
-        match self._instrument.word_currents_from_address(addr) {
+        match self._instrument.lock().unwrap().word_currents_from_address(addr) {
             Ok(result) => Ok(Array::from(result).into_pyarray(py)),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -1158,7 +2346,7 @@ impl PyInstrument {
     /// :return: An array with the currents of all bitline-corresponding channels
     /// :rtype: A numpy f32 array
     fn bit_currents_from_address<'py>(&self, py: Python<'py>, addr: u32) -> PyResult<&'py PyArray<f32, Ix1>> {
-        match self._instrument.bit_currents_from_address(addr) {
+        match self._instrument.lock().unwrap().bit_currents_from_address(addr) {
             Ok(result) => Ok(Array::from(result).into_pyarray(py)),
             Err(err) => Err(ArC2Error::new_exception(err))
         }
@@ -1193,7 +2381,7 @@ impl PyInstrument {
         pw_nanos: u128, inter_nanos: u128, num_pulses: usize,
         read_at: PyReadAt, read_after: PyReadAfter) -> PyResult<PyRefMut<'py, Self>> {
 
-        match slf._instrument.generate_ramp(low, high, vstart, vstep, vstop,
+        match slf._instrument.lock().unwrap().generate_ramp(low, high, vstart, vstep, vstop,
             pw_nanos, inter_nanos, num_pulses, read_at.into(),
             read_after.into()) {
             Ok(_) => Ok(slf),
@@ -1202,6 +2390,144 @@ impl PyInstrument {
 
     }
 
+    /// generate_ramp_scan(self, low, high, scan, pw_nanos, inter_nanos, num_pulses, readat,
+    /// readafter, /)
+    /// --
+    ///
+    /// Initiate a ramp operation driven by an arbitrary, already-materialized
+    /// :class:`~pyarc2.VoltageScan` instead of the monotonic ``vstart``/``vstep``/``vstop``
+    /// schedule of :meth:`~pyarc2.Instrument.generate_ramp`. This allows center scans,
+    /// randomized or bidirectional point orders to be queued while still honouring
+    /// ``read_at``/``read_after`` like a regular ramp. Results must be retrieved the same
+    /// way, with :meth:`~pyarc2.Instrument.get_iter` or :meth:`~pyarc2.Instrument.pick_one`.
+    ///
+    /// :param int low: The low voltage channel (typ. grounded)
+    /// :param int high: The high voltage channel
+    /// :param scan: A :class:`~pyarc2.VoltageScan` with the materialized voltage points
+    /// :param int pw_nanos: The pulse width for each individual pulse in nanoseconds
+    /// :param int inter_nanos: Delay between consecutive pulses in nanoseconds
+    /// :param int num_pulses: Number of pulses per individual voltage point
+    /// :param read_at: Variant of :class:`pyarc2.ReadAt` denoting the voltage (if any)
+    ///                 of read-out operations (if any)
+    /// :param read_after: Variant of :class:`pyarc2.ReadAfter` denoting when read-outs
+    ///                    will be done (if ever)
+    fn generate_ramp_scan<'py>(mut slf: PyRefMut<'py, Self>, low: usize, high: usize,
+        scan: PyVoltageScan, pw_nanos: u128, inter_nanos: u128, num_pulses: usize,
+        read_at: PyReadAt, read_after: PyReadAfter) -> PyResult<PyRefMut<'py, Self>> {
+
+        let read_at: ReadAt = read_at.into();
+        let read_after: ReadAfter = read_after.into();
+
+        for v in scan._voltages.iter() {
+            // `vstart == vstop` collapses the ramp to a single point regardless of
+            // `vstep`, so a nonzero step (here `1.0`) is used purely to keep the
+            // underlying ramp machinery's step/point-count arithmetic well-defined;
+            // it never actually moves the voltage since the span is zero.
+            let result = slf._instrument.lock().unwrap().generate_ramp(low, high, *v, 1.0, *v,
+                pw_nanos, inter_nanos, num_pulses, read_at.clone(), read_after.clone());
+
+            if let Err(err) = result {
+                return Err(ArC2Error::new_exception(err));
+            }
+        }
+
+        Ok(slf)
+    }
+
+    /// load_waveform(self, channel, segments, /)
+    /// --
+    ///
+    /// Define an arbitrary piecewise-constant waveform on ``channel`` as a sequence of
+    /// ``(voltage, duration_ns, read)`` segments, ready to be replayed back-to-back with
+    /// :meth:`~pyarc2.Instrument.play_waveform` without per-segment round-trips to Python.
+    /// This lets callers build potentiation/depression trains such as triangular or
+    /// staircase STDP-style waveforms ahead of time. Calling this again for the same
+    /// ``channel`` replaces the previously loaded waveform.
+    ///
+    /// :param int channel: The channel the waveform will be played on
+    /// :param segments: A list of ``(voltage, duration_ns, read)`` tuples; ``read``
+    ///                  flags whether a current read should be captured at that segment
+    /// :raises ValueError: If a segment voltage is outside the DAC range or a segment
+    ///                     has zero duration
+    fn load_waveform<'py>(mut slf: PyRefMut<'py, Self>, channel: usize,
+        segments: Vec<(f32, u64, bool)>) -> PyResult<PyRefMut<'py, Self>> {
+
+        for (voltage, duration, _) in segments.iter() {
+            if *voltage < DAC_VOLTAGE_MIN || *voltage > DAC_VOLTAGE_MAX {
+                return Err(exceptions::PyValueError::new_err(
+                    format!("Segment voltage {} is outside the DAC range [{}, {}]",
+                        voltage, DAC_VOLTAGE_MIN, DAC_VOLTAGE_MAX)));
+            }
+            if *duration == 0 {
+                return Err(exceptions::PyValueError::new_err(
+                    "Segments cannot have zero duration"));
+            }
+        }
+
+        slf._waveforms.insert(channel, segments);
+
+        Ok(slf)
+    }
+
+    /// play_waveform(self, channel, /)
+    /// --
+    ///
+    /// Play back the waveform previously defined on ``channel`` with
+    /// :meth:`~pyarc2.Instrument.load_waveform`. Consecutive segments not flagged ``read``
+    /// are packed into the command buffer and played back-to-back in a single contiguous
+    /// program; the buffer is only flushed early when a segment flagged ``read`` needs its
+    /// current captured, and return the reads so captured.
+    ///
+    /// :param int channel: The channel to play the waveform on
+    /// :return: An array with one entry per segment; entries for segments not
+    ///          flagged ``read`` are ``NaN``
+    /// :rtype: A numpy f32 array
+    /// :raises ValueError: If no waveform has been loaded for ``channel``
+    fn play_waveform<'py>(mut slf: PyRefMut<'py, Self>, py: Python<'py>, channel: usize)
+        -> PyResult<&'py PyArray<f32, Ix1>> {
+
+        let segments = match slf._waveforms.get(&channel) {
+            Some(segs) => segs.clone(),
+            None => return Err(exceptions::PyValueError::new_err(
+                "No waveform loaded for this channel; call load_waveform() first"))
+        };
+
+        let mut reads: Vec<f32> = Vec::with_capacity(segments.len());
+
+        // Segments are packed into the command buffer as they're encountered and only
+        // flushed with `execute()` when a read is actually needed (reading the real
+        // current requires the pending segments to have run first) or once at the very
+        // end. Runs of non-``read`` segments therefore play back-to-back in a single
+        // contiguous program instead of round-tripping to the host between every step.
+        for (voltage, duration, read) in segments.iter() {
+
+            if let Err(err) = slf._instrument.lock().unwrap().config_channels(&[(*channel as u16, *voltage)], None) {
+                return Err(ArC2Error::new_exception(err));
+            }
+            if let Err(err) = slf._instrument.lock().unwrap().add_delay(*duration as u128) {
+                return Err(ArC2Error::new_exception(err));
+            }
+
+            if *read {
+                if let Err(err) = slf._instrument.lock().unwrap().execute() {
+                    return Err(ArC2Error::new_exception(err));
+                }
+                match slf._instrument.lock().unwrap().read_slice_open(&[channel], false) {
+                    Ok(data) => reads.push(data[0]),
+                    Err(err) => return Err(ArC2Error::new_exception(err))
+                }
+            } else {
+                reads.push(f32::NAN);
+            }
+        }
+
+        if let Err(err) = slf._instrument.lock().unwrap().execute() {
+            return Err(ArC2Error::new_exception(err));
+        }
+
+        Ok(Array::from(reads).into_pyarray(py))
+    }
+
     /// generate_read_train(self, lows, highs, vread, nreads, inter_nanos, ground, /)
     /// --
     ///
@@ -1236,7 +2562,7 @@ impl PyInstrument {
                 None => vec![]
             };
 
-            match slf._instrument.generate_read_train(&low_chans, high_chans,
+            match slf._instrument.lock().unwrap().generate_read_train(&low_chans, high_chans,
                 vread, nreads, inter_nanos, ground) {
 
                 Ok(_) => Ok(slf),
@@ -1261,7 +2587,7 @@ impl PyInstrument {
 
         let chans = uchans.as_slice().unwrap();
 
-        match slf._instrument.generate_vread_train(chans, averaging, npulses,
+        match slf._instrument.lock().unwrap().generate_vread_train(chans, averaging, npulses,
             inter_nanos) {
 
             Ok(_) => Ok(slf),
@@ -1285,7 +2611,7 @@ impl PyInstrument {
         vread: f32, interpulse: u64, preload: Option<f32>, condition: PyWaitFor)
         -> PyResult<()> {
 
-        match slf._instrument.read_train(low, high, vread, interpulse as u128,
+        match slf._instrument.lock().unwrap().read_train(low, high, vread, interpulse as u128,
             preload, condition.into()) {
             Ok(_) => Ok(()),
             Err(err) => Err(ArC2Error::new_exception(err))
@@ -1311,7 +2637,7 @@ impl PyInstrument {
         let mode: DataMode = mode.into();
         let rtype: ReadType = rtype.into();
 
-        match self._instrument.pick_one(mode, rtype) {
+        match self._instrument.lock().unwrap().pick_one(mode, rtype) {
             Ok(data_opt) => {
                 match data_opt {
                     Some(data) => {
@@ -1326,6 +2652,291 @@ impl PyInstrument {
 
     }
 
+    /// stream_reads(self, mode, rtype, wait_for, chunk, /)
+    /// --
+    ///
+    /// Return a non-blocking iterator over the results of a long-running acquisition
+    /// such as :meth:`~pyarc2.Instrument.read_train` or
+    /// :meth:`~pyarc2.Instrument.generate_ramp`, driven by
+    /// :meth:`~pyarc2.Instrument.pick_one` under the hood. Each call to ``next()`` on the
+    /// returned iterator blocks only until the next ``chunk`` worth of samples has landed
+    /// in ArC2 memory or the operation completes, instead of forcing the caller to wait
+    /// for the whole acquisition to finish.
+    ///
+    /// :param mode: A variant of :class:`pyarc2.DataMode` selecting words/bits/all
+    /// :param rtype: A variant of :class:`pyarc2.ReadType`
+    /// :param wait_for: The :class:`pyarc2.WaitFor` condition the originating operation
+    ///                  was started with; used to report expected progress when it is
+    ///                  an iteration count
+    /// :param int chunk: The number of samples to batch per ``next()`` call
+    /// :return: An iterator yielding numpy chunks of shape ``(n, width)`` with ``n`` up to
+    ///          ``chunk``; the last chunk can hold fewer rows
+    /// :rtype: pyarc2.ReadStream
+    fn stream_reads(slf: &PyCell<Self>, mode: PyDataMode, rtype: PyReadType,
+        wait_for: PyWaitFor, chunk: usize) -> PyReadStream {
+
+        PyReadStream {
+            instrument: Py::from(slf),
+            mode: mode.into(),
+            rtype: rtype.into(),
+            wait_for: wait_for.into(),
+            chunk,
+            retrieved: 0,
+            done: false
+        }
+    }
+
+    /// stream_to(self, path, mode, rtype, chunk, compression, /)
+    /// --
+    ///
+    /// Drain the internal long-operation buffer and write it incrementally to a chunked,
+    /// compressed HDF5 dataset at ``path``, so a run of unbounded length (long retention
+    /// or endurance sweeps) streams to disk without ever materializing the full array in
+    /// memory. This method stamps the dataset with the acquisition's
+    /// :class:`~pyarc2.DataMode`, :class:`~pyarc2.ReadType` and the firmware
+    /// :data:`pyarc2.LIBARC2_VERSION` only; the channel mask and timestamps are not
+    /// recorded, and the sink is closed before this method returns.
+    ///
+    /// :param str path: Destination HDF5 file
+    /// :param mode: A variant of :class:`pyarc2.DataMode`
+    /// :param rtype: A variant of :class:`pyarc2.ReadType`
+    /// :param int chunk: The number of records to batch per write
+    /// :param str compression: One of ``gzip``, ``lzf`` or ``none``
+    fn stream_to<'py>(&mut self, py: Python<'py>, path: &str, mode: PyDataMode,
+        rtype: PyReadType, chunk: usize, compression: &str) -> PyResult<()> {
+
+        let mode: DataMode = mode.into();
+        let rtype: ReadType = rtype.into();
+        let width = match mode { DataMode::All => 64, _ => 32 };
+
+        let mut sink = PyDataSink::new(path, width, compression)?;
+        sink.set_meta("DataMode", &format!("{:?}", mode))?;
+        sink.set_meta("ReadType", &format!("{:?}", rtype))?;
+        sink.set_meta("LIBARC2_VERSION", libarc2::LIBARC2_VERSION)?;
+
+        const EMPTY_POLL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(1);
+
+        loop {
+            let instrument = self._instrument.clone();
+
+            // Poll with the GIL released so an empty-but-busy acquisition backs off
+            // with a short sleep instead of pegging a core and blocking every other
+            // Python thread for the whole run.
+            let (batch, nrows, done) = py.allow_threads(move || {
+                let mut batch: Vec<f32> = Vec::new();
+                let mut nrows = 0usize;
+                let mut done = false;
+
+                while nrows < chunk {
+                    match instrument.lock().unwrap().pick_one(mode.clone(), rtype.clone()) {
+                        Ok(Some(data)) => {
+                            batch.extend(data);
+                            nrows += 1;
+                        },
+                        Ok(None) => {
+                            if !instrument.lock().unwrap().busy() {
+                                done = true;
+                                break;
+                            }
+                            std::thread::sleep(EMPTY_POLL_BACKOFF);
+                        },
+                        Err(err) => return Err(ArC2Error::new_exception(err))
+                    }
+                }
+
+                Ok((batch, nrows, done))
+            })?;
+
+            if nrows > 0 {
+                let array = Array::from_shape_vec((nrows, width), batch).unwrap();
+                sink.append(array.into_pyarray(py).readonly())?;
+            }
+
+            if nrows == 0 && done {
+                break;
+            }
+        }
+
+        sink.close()
+    }
+
+    /// subscribe(self, mode, rtype, receiver, /)
+    /// --
+    ///
+    /// Spawn a background worker that watches the internal long-operation buffer and
+    /// pushes every slab to ``receiver`` as soon as it is ready. ``receiver`` can either
+    /// be a callable (invoked with the GIL held as ``receiver(data)``) or a
+    /// ``queue.Queue``-like object (fed via ``receiver.put(data)``), so GUIs and live
+    /// plotters can react to data as the hardware produces it instead of spinning on
+    /// :meth:`~pyarc2.Instrument.pick_one`. The instrument is shared with the worker
+    /// through the same lock every other :class:`~pyarc2.Instrument` method takes, so
+    /// calling another instrument method while subscribed blocks until the worker
+    /// yields it rather than racing with it. Call :meth:`~pyarc2.Instrument.unsubscribe`
+    /// to stop the worker; it is also stopped and joined automatically if this
+    /// instrument is garbage collected while still subscribed.
+    ///
+    /// :param mode: A variant of :class:`pyarc2.DataMode`
+    /// :param rtype: A variant of :class:`pyarc2.ReadType`
+    /// :param receiver: A callable or a ``queue.Queue``-like object with a ``put`` method
+    /// :raises RuntimeError: If a subscription is already active
+    fn subscribe(&mut self, mode: PyDataMode, rtype: PyReadType, receiver: PyObject)
+        -> PyResult<()> {
+
+        if self._subscription.is_some() {
+            return Err(exceptions::PyRuntimeError::new_err(
+                "Already subscribed; call unsubscribe() first"));
+        }
+
+        const EMPTY_POLL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(1);
+
+        let mode: DataMode = mode.into();
+        let rtype: ReadType = rtype.into();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_worker = stop.clone();
+        let instrument = self._instrument.clone();
+
+        let handle = std::thread::spawn(move || {
+
+            while !stop_worker.load(Ordering::Relaxed) {
+                let picked = instrument.lock().unwrap().pick_one(mode.clone(), rtype.clone());
+
+                match picked {
+                    Ok(Some(data)) => {
+                        Python::with_gil(|py| {
+                            let array = Array::from(data).into_pyarray(py);
+
+                            let delivered = if receiver.as_ref(py).is_callable() {
+                                receiver.call1(py, (array,))
+                            } else {
+                                receiver.call_method1(py, "put", (array,))
+                            };
+
+                            let _ = delivered;
+                        });
+                    },
+                    Ok(None) => {
+                        if !instrument.lock().unwrap().busy() {
+                            break;
+                        }
+                        std::thread::sleep(EMPTY_POLL_BACKOFF);
+                    },
+                    Err(_) => break
+                }
+            }
+        });
+
+        self._subscription = Some((stop, handle));
+        Ok(())
+    }
+
+    /// unsubscribe(self, /)
+    /// --
+    ///
+    /// Stop the background worker started by :meth:`~pyarc2.Instrument.subscribe`, if
+    /// any, and block until it has fully torn down.
+    fn unsubscribe(&mut self, py: Python) {
+        if let Some((stop, handle)) = self._subscription.take() {
+            stop.store(true, Ordering::Relaxed);
+            // The worker takes the GIL to deliver data, so joining it while we hold
+            // the GIL ourselves could deadlock if it's parked in Python::with_gil.
+            py.allow_threads(|| { let _ = handle.join(); });
+        }
+    }
+
+}
+
+/// Non-blocking iterator over chunks of a long-running acquisition, returned by
+/// :meth:`pyarc2.Instrument.stream_reads`.
+#[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
+#[pyclass(name="ReadStream", module="pyarc2")]
+struct PyReadStream {
+    instrument: Py<PyInstrument>,
+    mode: DataMode,
+    rtype: ReadType,
+    wait_for: WaitFor,
+    chunk: usize,
+    retrieved: usize,
+    done: bool
+}
+
+#[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
+#[pymethods]
+impl PyReadStream {
+
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__<'py>(mut slf: PyRefMut<'py, Self>, py: Python<'py>)
+        -> PyResult<Option<&'py PyArray<f32, Ix2>>> {
+
+        if slf.done {
+            return Ok(None);
+        }
+
+        const EMPTY_POLL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(1);
+
+        let mode = slf.mode.clone();
+        let rtype = slf.rtype.clone();
+        let chunk = slf.chunk;
+        let instrument = slf.instrument.borrow(py)._instrument.clone();
+
+        // Release the GIL while polling so an empty-but-busy acquisition backs off
+        // with a short sleep instead of pegging a core and blocking every other
+        // Python thread until the whole acquisition finishes.
+        let (rows, width, nrows, done) = py.allow_threads(move || {
+            let mut rows: Vec<f32> = Vec::new();
+            let mut width = 0usize;
+            let mut nrows = 0usize;
+            let mut done = false;
+
+            while nrows < chunk {
+                match instrument.lock().unwrap().pick_one(mode.clone(), rtype.clone()) {
+                    Ok(Some(data)) => {
+                        width = data.len();
+                        rows.extend(data);
+                        nrows += 1;
+                    },
+                    Ok(None) => {
+                        if !instrument.lock().unwrap().busy() {
+                            done = true;
+                            break;
+                        }
+                        std::thread::sleep(EMPTY_POLL_BACKOFF);
+                    },
+                    Err(err) => return Err(ArC2Error::new_exception(err))
+                }
+            }
+
+            Ok((rows, width, nrows, done))
+        })?;
+
+        if done {
+            slf.done = true;
+        }
+
+        slf.retrieved += nrows;
+
+        if nrows == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(Array::from_shape_vec((nrows, width), rows).unwrap().into_pyarray(py)))
+    }
+
+    /// progress(self, /)
+    /// --
+    ///
+    /// :return: A tuple of ``(samples retrieved so far, expected total)``. The expected
+    ///          total is ``None`` unless this stream's originating operation was started
+    ///          with :meth:`pyarc2.WaitFor.Iterations`.
+    /// :rtype: (int, Optional[int])
+    fn progress(&self) -> (usize, Option<usize>) {
+        match self.wait_for {
+            WaitFor::Iterations(n) => (self.retrieved, Some(n)),
+            _ => (self.retrieved, None)
+        }
+    }
 }
 
 #[pymodule]
@@ -1352,16 +2963,36 @@ fn pyarc2(py: Python, m: &PyModule) -> PyResult<()> {
 
     #[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
     m.add_class::<PyInstrument>()?;
+    #[cfg(all(any(target_os = "windows", target_os = "linux"), target_arch = "x86_64"))]
+    m.add_class::<PyReadStream>()?;
+    m.add_class::<PyDataSink>()?;
 
     m.add_class::<PyBiasOrder>()?;
     m.add_class::<PyControlMode>()?;
     m.add_class::<PyDataMode>()?;
     m.add_class::<PyReadType>()?;
+    m.add_class::<PyInfoKey>()?;
     m.add_class::<PyReadAt>()?;
     m.add_class::<PyReadAfter>()?;
     m.add_class::<PyWaitFor>()?;
+    m.add_class::<PyVoltageScan>()?;
+    m.add_class::<PyRangeScan>()?;
+    m.add_class::<PyCenterScan>()?;
     m.add_class::<PyAuxDACFn>()?;
+    m.add_class::<PyErrorCategory>()?;
     m.add("ArC2Error", py.get_type::<ArC2Error>())?;
+    m.add("FPGACommError", py.get_type::<FPGACommError>())?;
+    m.add("MemoryAccessError", py.get_type::<MemoryAccessError>())?;
+    m.add("InvalidDeviceIDError", py.get_type::<InvalidDeviceIDError>())?;
+    m.add("RampConsistencyError", py.get_type::<RampConsistencyError>())?;
+    m.add("OutputBufferError", py.get_type::<OutputBufferError>())?;
+    // Aliases under the names originally requested for this hierarchy
+    // (comm/range/device). They are the same exception types as above, not
+    // distinct subclasses, since libarc2's error type carries no numeric
+    // code beyond what ErrCategory already models; see the note on `code`.
+    m.add("ArC2CommError", py.get_type::<FPGACommError>())?;
+    m.add("ArC2RangeError", py.get_type::<RampConsistencyError>())?;
+    m.add("ArC2DeviceError", py.get_type::<InvalidDeviceIDError>())?;
 
     m.setattr(intern!(m.py(), "LIBARC2_VERSION"), libarc2::LIBARC2_VERSION)?;
 